@@ -26,34 +26,282 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use std::any::TypeId;
 use std::io::{Cursor, ErrorKind};
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use bevy::{
     asset::{io::Reader, AssetLoader, AssetPath, AsyncReadExt},
+    ecs::reflect::ReflectComponent,
     log,
+    math::IVec2,
     prelude::{
-        Added, Asset, AssetApp, AssetEvent, AssetId, Assets, Bundle, Commands, Component,
-        DespawnRecursiveExt, Entity, EventReader, GlobalTransform, Handle, Image, Plugin, Query,
-        Res, Transform, Update,
+        Added, App, AppTypeRegistry, Asset, AssetApp, AssetEvent, AssetId, Assets, Bundle, Color,
+        Commands, Component, DespawnRecursiveExt, Entity, EventReader, FromWorld, GlobalTransform,
+        Handle, Image, Plugin, Query, Res, Resource, Time, Timer, TimerMode, Transform, Update,
+        World,
     },
-    reflect::TypePath,
+    reflect::{FromReflect, Reflect, ReflectDefault, ReflectMut, TypePath},
     utils::HashMap,
 };
 use bevy_ecs_tilemap::prelude::*;
 
+/// The shape of a [`TiledObject`] spawned from a Tiled object layer, mirroring
+/// the variants of `tiled::ObjectShape`.
+#[derive(Clone, Debug)]
+pub enum TiledObjectShape {
+    Point,
+    Rect { width: f32, height: f32 },
+    Ellipse { width: f32, height: f32 },
+    Polygon { points: Vec<(f32, f32)> },
+    Polyline { points: Vec<(f32, f32)> },
+    Text { text: String },
+    /// A tile object, referencing a tile by its tileset and local id within
+    /// that tileset (local ids repeat across tilesets, so both are required
+    /// to resolve "which tile").
+    Tile { tileset_index: usize, id: u32 },
+}
+
+/// Spawned for every object found in a Tiled object layer. Carries the data
+/// needed by gameplay code to recognize and use the object (spawn points,
+/// collision rects, triggers, etc.) without re-parsing the map.
+#[derive(Component, Clone, Debug)]
+pub struct TiledObject {
+    pub id: u32,
+    pub name: String,
+    pub user_type: String,
+    pub shape: TiledObjectShape,
+}
+
+/// Drives a tile through a non-contiguous sequence of `TileTextureIndex` values,
+/// each shown for its own Tiled-authored duration. Contiguous animations are
+/// instead handled by bevy_ecs_tilemap's own [`AnimatedTile`], which is cheaper.
+#[derive(Component)]
+pub struct TiledAnimatedTile {
+    frames: Vec<(u32, Duration)>,
+    current_frame: usize,
+    timer: Timer,
+}
+
+impl TiledAnimatedTile {
+    /// Returns `None` for an empty frame list (a malformed TMX can declare a
+    /// tile animation with zero frames), since there's nothing to play.
+    fn new(frames: Vec<(u32, Duration)>) -> Option<Self> {
+        let duration = frames.first()?.1;
+        Some(Self {
+            frames,
+            current_frame: 0,
+            timer: Timer::new(duration, TimerMode::Repeating),
+        })
+    }
+}
+
+fn animate_tiled_tiles(
+    time: Res<Time>,
+    mut query: Query<(&mut TiledAnimatedTile, &mut TileTextureIndex)>,
+) {
+    for (mut animated_tile, mut texture_index) in &mut query {
+        animated_tile.timer.tick(time.delta());
+        if !animated_tile.timer.just_finished() {
+            continue;
+        }
+
+        animated_tile.current_frame =
+            (animated_tile.current_frame + 1) % animated_tile.frames.len();
+        let (index, duration) = animated_tile.frames[animated_tile.current_frame];
+        texture_index.0 = index;
+        animated_tile.timer.set_duration(duration);
+    }
+}
+
 #[derive(Default)]
 pub struct TiledMapPlugin;
 
 impl Plugin for TiledMapPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.init_asset::<TiledMap>()
-            .register_asset_loader(TiledLoader)
-            .add_systems(Update, process_loaded_maps);
+            .init_resource::<TiledResourceCache>()
+            .init_asset_loader::<TiledLoader>()
+            .add_systems(Update, (process_loaded_maps, animate_tiled_tiles));
+    }
+}
+
+/// Maps a Tiled class (the `user_type` Tiled 1.9 attaches to objects and tiles)
+/// to the [`TypeId`] of the Bevy component registered for it, so
+/// `process_loaded_maps` knows what to build via reflection.
+#[derive(Resource, Default)]
+struct TiledClassRegistry {
+    objects: HashMap<String, TypeId>,
+    tiles: HashMap<String, TypeId>,
+}
+
+/// Registers Tiled classes as Bevy components, so maps authored entirely in the
+/// Tiled editor can attach strongly-typed gameplay data without post-processing.
+pub trait TiledAppExt {
+    /// Insert `T` on every object entity whose Tiled class is `class_name`,
+    /// populating its fields from the object's custom properties by name.
+    ///
+    /// `T` must derive `#[reflect(Default, Component)]` in addition to the bounds
+    /// below — without both, nothing is inserted and a warning is logged instead.
+    fn register_tiled_object<T>(&mut self, class_name: &str) -> &mut Self
+    where
+        T: Component + Reflect + FromReflect + Default;
+
+    /// Insert `T` on every tile entity whose source tile's Tiled class is
+    /// `class_name`, populating its fields from the tile's custom properties by name.
+    ///
+    /// `T` must derive `#[reflect(Default, Component)]` in addition to the bounds
+    /// below — without both, nothing is inserted and a warning is logged instead.
+    fn register_tiled_tile<T>(&mut self, class_name: &str) -> &mut Self
+    where
+        T: Component + Reflect + FromReflect + Default;
+}
+
+impl TiledAppExt for App {
+    fn register_tiled_object<T>(&mut self, class_name: &str) -> &mut Self
+    where
+        T: Component + Reflect + FromReflect + Default,
+    {
+        self.register_type::<T>();
+        self.world
+            .get_resource_or_insert_with(TiledClassRegistry::default)
+            .objects
+            .insert(class_name.to_string(), TypeId::of::<T>());
+        self
+    }
+
+    fn register_tiled_tile<T>(&mut self, class_name: &str) -> &mut Self
+    where
+        T: Component + Reflect + FromReflect + Default,
+    {
+        self.register_type::<T>();
+        self.world
+            .get_resource_or_insert_with(TiledClassRegistry::default)
+            .tiles
+            .insert(class_name.to_string(), TypeId::of::<T>());
+        self
+    }
+}
+
+/// Sets `field` from a Tiled `value`, coercing Tiled's Int/Float/Bool/String/Color/
+/// Object property types to whatever concrete numeric/string/color type the field
+/// actually is. Unlike `Reflect::apply`, this never panics on a type mismatch — it
+/// logs a warning and leaves the field at its default instead.
+fn apply_property_value(field: &mut dyn Reflect, field_name: &str, value: &tiled::PropertyValue) {
+    let applied = match value {
+        tiled::PropertyValue::BoolValue(v) => field.downcast_mut::<bool>().map(|f| *f = *v),
+        tiled::PropertyValue::IntValue(v) => field
+            .downcast_mut::<i8>()
+            .map(|f| *f = *v as i8)
+            .or_else(|| field.downcast_mut::<i16>().map(|f| *f = *v as i16))
+            .or_else(|| field.downcast_mut::<i32>().map(|f| *f = *v))
+            .or_else(|| field.downcast_mut::<i64>().map(|f| *f = *v as i64))
+            .or_else(|| field.downcast_mut::<u8>().map(|f| *f = *v as u8))
+            .or_else(|| field.downcast_mut::<u16>().map(|f| *f = *v as u16))
+            .or_else(|| field.downcast_mut::<u32>().map(|f| *f = *v as u32))
+            .or_else(|| field.downcast_mut::<u64>().map(|f| *f = *v as u64))
+            .or_else(|| field.downcast_mut::<f32>().map(|f| *f = *v as f32))
+            .or_else(|| field.downcast_mut::<f64>().map(|f| *f = *v as f64)),
+        tiled::PropertyValue::FloatValue(v) => field
+            .downcast_mut::<f32>()
+            .map(|f| *f = *v)
+            .or_else(|| field.downcast_mut::<f64>().map(|f| *f = *v as f64))
+            .or_else(|| field.downcast_mut::<i32>().map(|f| *f = *v as i32))
+            .or_else(|| field.downcast_mut::<u32>().map(|f| *f = *v as u32)),
+        tiled::PropertyValue::StringValue(v) => {
+            field.downcast_mut::<String>().map(|f| *f = v.clone())
+        }
+        tiled::PropertyValue::FileValue(v) => {
+            field.downcast_mut::<String>().map(|f| *f = v.clone())
+        }
+        tiled::PropertyValue::ColorValue(v) => field
+            .downcast_mut::<Color>()
+            .map(|f| {
+                *f = Color::rgba(
+                    v.red as f32 / 255.0,
+                    v.green as f32 / 255.0,
+                    v.blue as f32 / 255.0,
+                    v.alpha as f32 / 255.0,
+                )
+            })
+            .or_else(|| {
+                field.downcast_mut::<[f32; 4]>().map(|f| {
+                    *f = [
+                        v.red as f32 / 255.0,
+                        v.green as f32 / 255.0,
+                        v.blue as f32 / 255.0,
+                        v.alpha as f32 / 255.0,
+                    ]
+                })
+            }),
+        tiled::PropertyValue::ObjectValue(v) => field
+            .downcast_mut::<u32>()
+            .map(|f| *f = *v)
+            .or_else(|| field.downcast_mut::<u64>().map(|f| *f = *v as u64)),
+        _ => None,
+    };
+
+    if applied.is_none() {
+        log::warn!(
+            "Could not coerce Tiled property {field_name:?} ({value:?}) into field of type {}",
+            field.type_name()
+        );
     }
 }
 
+/// Builds a default `T` registered for `type_id`, fills its fields from `properties`
+/// by matching property names to struct field names (coercing Tiled's property
+/// types to the field's type), and inserts it on `entity`.
+fn insert_tiled_class_component(
+    commands: &mut Commands,
+    entity: Entity,
+    type_id: TypeId,
+    properties: tiled::Properties,
+) {
+    commands.add(move |world: &mut World| {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let Some(registration) = registry.get(type_id) else {
+            log::warn!(
+                "Tiled class registered for a type that isn't in the type registry; \
+                 did you forget `app.register_type::<T>()`?"
+            );
+            return;
+        };
+        let Some(reflect_default) = registration.data::<ReflectDefault>() else {
+            log::warn!(
+                "Tiled class component {} is missing #[reflect(Default)]; \
+                 it won't be inserted on matching tiles/objects.",
+                registration.type_info().type_path()
+            );
+            return;
+        };
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            log::warn!(
+                "Tiled class component {} is missing #[reflect(Component)]; \
+                 it won't be inserted on matching tiles/objects.",
+                registration.type_info().type_path()
+            );
+            return;
+        };
+
+        let mut instance = reflect_default.default();
+        if let ReflectMut::Struct(s) = instance.reflect_mut() {
+            for (name, value) in properties.iter() {
+                let Some(field) = s.field_mut(name) else {
+                    continue;
+                };
+                apply_property_value(field, name, value);
+            }
+        }
+
+        let mut entity_mut = world.entity_mut(entity);
+        reflect_component.insert(&mut entity_mut, instance.as_ref(), &registry);
+    });
+}
+
 #[derive(TypePath, Asset)]
 pub struct TiledMap {
     pub map: tiled::Map,
@@ -63,12 +311,18 @@ pub struct TiledMap {
     // The offset into the tileset_images for each tile id within each tileset.
     #[cfg(not(feature = "atlas"))]
     pub tile_image_offsets: HashMap<(usize, tiled::TileId), u32>,
+
+    // Per-tile animation frames, keyed by (tileset_index, tile_id) of the animated tile.
+    pub tile_animations: HashMap<(usize, tiled::TileId), Vec<tiled::Frame>>,
 }
 
 // Stores a list of tiled layers.
 #[derive(Component, Default)]
 pub struct TiledLayersStorage {
     pub storage: HashMap<u32, Entity>,
+    // Entities spawned for the map's object layers, so they can be despawned on
+    // reload instead of re-spawning a duplicate set on top of the old ones.
+    pub objects: Vec<Entity>,
 }
 
 #[derive(Default, Bundle)]
@@ -102,7 +356,68 @@ impl tiled::ResourceReader for BytesResourceReader {
     }
 }
 
-pub struct TiledLoader;
+/// Shared cache of parsed tilesets and resolved tileset/tile image handles,
+/// injected into every [`TiledLoader`]. Two maps referencing the same external
+/// `.tsx` tileset (or the same tileset image) reuse the same parse and the
+/// same `Handle<Image>` instead of duplicating both per map.
+#[derive(Resource, Clone, Default)]
+pub struct TiledResourceCache {
+    tilesets: Arc<Mutex<tiled::DefaultResourceCache>>,
+    images: Arc<Mutex<HashMap<String, Handle<Image>>>>,
+}
+
+/// Adapts a [`TiledResourceCache`]'s tileset cache to rs-tiled's
+/// [`tiled::ResourceCache`] trait, so the same cache can be handed to
+/// `tiled::Loader` across every map load.
+struct SharedResourceCache(Arc<Mutex<tiled::DefaultResourceCache>>);
+
+impl tiled::ResourceCache for SharedResourceCache {
+    fn get_tileset(&self, path: impl AsRef<Path>) -> Option<Arc<tiled::Tileset>> {
+        self.0.lock().unwrap().get_tileset(path)
+    }
+
+    fn get_or_try_insert_tileset_with<F, E>(
+        &mut self,
+        path: PathBuf,
+        f: F,
+    ) -> Result<Arc<tiled::Tileset>, E>
+    where
+        F: FnOnce() -> Result<tiled::Tileset, E>,
+    {
+        self.0.lock().unwrap().get_or_try_insert_tileset_with(path, f)
+    }
+}
+
+pub struct TiledLoader {
+    cache: TiledResourceCache,
+}
+
+impl FromWorld for TiledLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            cache: world.get_resource_or_insert_with(TiledResourceCache::default).clone(),
+        }
+    }
+}
+
+impl TiledLoader {
+    // Interns `asset_path` against the shared image cache so identical tileset
+    // images across maps dedupe to a single `Handle<Image>` (and GPU texture).
+    fn load_image(
+        &self,
+        load_context: &mut bevy::asset::LoadContext,
+        asset_path: AssetPath<'static>,
+    ) -> Handle<Image> {
+        let key = asset_path.path().to_string_lossy().into_owned();
+        self.cache
+            .images
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_insert_with(|| load_context.load(asset_path))
+            .clone()
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum TiledAssetLoaderError {
@@ -126,7 +441,7 @@ impl AssetLoader for TiledLoader {
         reader.read_to_end(&mut bytes).await?;
 
         let mut loader = tiled::Loader::with_cache_and_reader(
-            tiled::DefaultResourceCache::new(),
+            SharedResourceCache(self.cache.tilesets.clone()),
             BytesResourceReader::new(&bytes),
         );
         let map = loader.load_tmx_map(load_context.path()).map_err(|e| {
@@ -136,8 +451,15 @@ impl AssetLoader for TiledLoader {
         let mut tilemap_textures = HashMap::default();
         #[cfg(not(feature = "atlas"))]
         let mut tile_image_offsets = HashMap::default();
+        let mut tile_animations = HashMap::default();
 
         for (tileset_index, tileset) in map.tilesets().iter().enumerate() {
+            for (tile_id, tile) in tileset.tiles() {
+                if let Some(animation) = &tile.animation {
+                    tile_animations.insert((tileset_index, tile_id), animation.clone());
+                }
+            }
+
             let tilemap_texture = match &tileset.image {
                 None => {
                     #[cfg(feature = "atlas")]
@@ -160,7 +482,7 @@ impl AssetLoader for TiledLoader {
                                 let tile_path = tmx_dir.join(&img.source);
                                 let asset_path = AssetPath::from(tile_path);
                                 log::info!("Loading tile image from {asset_path:?} as image ({tileset_index}, {tile_id})");
-                                let texture: Handle<Image> = load_context.load(asset_path.clone());
+                                let texture = self.load_image(load_context, asset_path);
                                 tile_image_offsets
                                     .insert((tileset_index, tile_id), tile_images.len() as u32);
                                 tile_images.push(texture.clone());
@@ -179,7 +501,7 @@ impl AssetLoader for TiledLoader {
                         .expect("The asset load context was empty.");
                     let tile_path = tmx_dir.join(&img.source);
                     let asset_path = AssetPath::from(tile_path);
-                    let texture: Handle<Image> = load_context.load(asset_path.clone());
+                    let texture = self.load_image(load_context, asset_path);
 
                     TilemapTexture::Single(texture.clone())
                 }
@@ -193,6 +515,7 @@ impl AssetLoader for TiledLoader {
             tilemap_textures,
             #[cfg(not(feature = "atlas"))]
             tile_image_offsets,
+            tile_animations,
         };
 
         log::info!("Loaded map: {}", load_context.path().display());
@@ -216,6 +539,7 @@ pub fn process_loaded_maps(
         &TilemapRenderSettings,
     )>,
     new_maps: Query<&Handle<TiledMap>, Added<Handle<TiledMap>>>,
+    class_registry: Option<Res<TiledClassRegistry>>,
 ) {
     let mut changed_maps = Vec::<AssetId<TiledMap>>::default();
     for event in map_events.read() {
@@ -259,6 +583,106 @@ pub fn process_loaded_maps(
                     }
                     // commands.entity(*layer_entity).despawn_recursive();
                 }
+                for object_entity in layer_storage.objects.drain(..) {
+                    commands.entity(object_entity).despawn_recursive();
+                }
+
+                // Object layers don't carry tile textures, so they're spawned once per map
+                // rather than inside the per-tileset loop below.
+                for layer in tiled_map.map.layers() {
+                    let tiled::LayerType::Objects(object_layer) = layer.layer_type() else {
+                        continue;
+                    };
+
+                    for object in object_layer.objects() {
+                        // Tile objects are anchored at their bottom-left corner, unlike every
+                        // other shape (anchored top-left), so track the referenced tile's
+                        // height to correct for that below.
+                        let mut tile_object_height = None;
+                        let shape = if let Some(tile) = object.get_tile() {
+                            tile_object_height = tiled_map
+                                .map
+                                .tilesets()
+                                .get(tile.tileset_index())
+                                .map(|tileset| tileset.tile_height as f32);
+                            TiledObjectShape::Tile {
+                                tileset_index: tile.tileset_index(),
+                                id: tile.id(),
+                            }
+                        } else {
+                            match &object.shape {
+                                tiled::ObjectShape::Rect { width, height } => {
+                                    TiledObjectShape::Rect {
+                                        width: *width,
+                                        height: *height,
+                                    }
+                                }
+                                tiled::ObjectShape::Ellipse { width, height } => {
+                                    TiledObjectShape::Ellipse {
+                                        width: *width,
+                                        height: *height,
+                                    }
+                                }
+                                tiled::ObjectShape::Polygon { points } => {
+                                    TiledObjectShape::Polygon {
+                                        points: points.clone(),
+                                    }
+                                }
+                                tiled::ObjectShape::Polyline { points } => {
+                                    TiledObjectShape::Polyline {
+                                        points: points.clone(),
+                                    }
+                                }
+                                tiled::ObjectShape::Point(_, _) => TiledObjectShape::Point,
+                                tiled::ObjectShape::Text { text, .. } => TiledObjectShape::Text {
+                                    text: text.clone(),
+                                },
+                            }
+                        };
+
+                        // Convert from TMX top-left pixel coords into Bevy's y-up,
+                        // center-origin space, same conversion already used for tiles.
+                        // Tile objects are the exception: fold in the tile's height
+                        // before flipping, since their y is the bottom-left anchor.
+                        let map_pixel_width =
+                            tiled_map.map.width as f32 * tiled_map.map.tile_width as f32;
+                        let map_pixel_height =
+                            tiled_map.map.height as f32 * tiled_map.map.tile_height as f32;
+                        let anchored_y = object.y - tile_object_height.unwrap_or(0.0);
+                        let transform = Transform::from_xyz(
+                            object.x - map_pixel_width / 2.0,
+                            map_pixel_height / 2.0 - anchored_y,
+                            0.0,
+                        );
+
+                        let object_entity = commands
+                            .spawn((
+                                TiledObject {
+                                    id: object.id(),
+                                    name: object.name.clone(),
+                                    user_type: object.user_type.clone(),
+                                    shape,
+                                },
+                                transform,
+                                GlobalTransform::default(),
+                            ))
+                            .id();
+
+                        if let Some(type_id) = class_registry
+                            .as_ref()
+                            .and_then(|r| r.objects.get(&object.user_type))
+                        {
+                            insert_tiled_class_component(
+                                &mut commands,
+                                object_entity,
+                                *type_id,
+                                object.properties.clone(),
+                            );
+                        }
+
+                        layer_storage.objects.push(object_entity);
+                    }
+                }
 
                 // The TilemapBundle requires that all tile images come exclusively from a single
                 // tiled texture or from a Vec of independent per-tile images. Furthermore, all of
@@ -283,10 +707,31 @@ pub fn process_loaded_maps(
                     };
 
                     // Once materials have been created/added we need to then create the layers.
+                    // The tile-offset feature lets a tileset nudge every tile it draws by a
+                    // fixed pixel amount; fold it into every layer's transform below.
+                    let tileset_offset = Transform::from_xyz(
+                        tileset.offset_x as f32,
+                        -tileset.offset_y as f32,
+                        0.0,
+                    );
+
                     for (layer_index, layer) in tiled_map.map.layers().enumerate() {
                         let offset_x = layer.offset_x;
                         let offset_y = layer.offset_y;
 
+                        let layer_tint = layer.tint_color.unwrap_or(tiled::Color {
+                            red: 255,
+                            green: 255,
+                            blue: 255,
+                            alpha: 255,
+                        });
+                        let layer_color = TilemapColor(Color::rgba(
+                            (layer_tint.red as f32 / 255.0) * layer.opacity,
+                            (layer_tint.green as f32 / 255.0) * layer.opacity,
+                            (layer_tint.blue as f32 / 255.0) * layer.opacity,
+                            (layer_tint.alpha as f32 / 255.0) * layer.opacity,
+                        ));
+
                         let tiled::LayerType::Tiles(tile_layer) = layer.layer_type() else {
                             log::info!(
                                 "Skipping layer {} because only tile layers are supported.",
@@ -295,17 +740,43 @@ pub fn process_loaded_maps(
                             continue;
                         };
 
-                        let tiled::TileLayer::Finite(layer_data) = tile_layer else {
-                            log::info!(
-                                "Skipping layer {} because only finite layers are supported.",
-                                layer.id()
-                            );
-                            continue;
-                        };
+                        // Finite layers are exactly as large as the map. Infinite layers are
+                        // stored as a sparse set of chunks and can extend into negative tile
+                        // coordinates, so their size and origin have to be derived from the
+                        // bounding box of all populated chunks.
+                        let (map_size, tile_origin) = match &tile_layer {
+                            tiled::TileLayer::Finite(_) => (
+                                TilemapSize {
+                                    x: tiled_map.map.width,
+                                    y: tiled_map.map.height,
+                                },
+                                IVec2::ZERO,
+                            ),
+                            tiled::TileLayer::Infinite(infinite_layer) => {
+                                let mut min = IVec2::splat(i32::MAX);
+                                let mut max = IVec2::splat(i32::MIN);
+                                for chunk in infinite_layer.chunks() {
+                                    min.x = min.x.min(chunk.x);
+                                    min.y = min.y.min(chunk.y);
+                                    max.x = max.x.max(chunk.x + chunk.width as i32 - 1);
+                                    max.y = max.y.max(chunk.y + chunk.height as i32 - 1);
+                                }
+                                if max.x < min.x || max.y < min.y {
+                                    log::info!(
+                                        "Skipping infinite layer {} because it has no chunks.",
+                                        layer.id()
+                                    );
+                                    continue;
+                                }
 
-                        let map_size = TilemapSize {
-                            x: tiled_map.map.width,
-                            y: tiled_map.map.height,
+                                (
+                                    TilemapSize {
+                                        x: (max.x - min.x + 1) as u32,
+                                        y: (max.y - min.y + 1) as u32,
+                                    },
+                                    min,
+                                )
+                            }
                         };
 
                         let grid_size = TilemapGridSize {
@@ -331,13 +802,12 @@ pub fn process_loaded_maps(
 
                         for x in 0..map_size.x {
                             for y in 0..map_size.y {
-                                // Transform TMX coords into bevy coords.
-                                let mapped_y = tiled_map.map.height - 1 - y;
+                                // Transform TMX coords into bevy coords, then shift by the
+                                // layer's tile origin (always zero for finite layers).
+                                let mapped_x = x as i32 + tile_origin.x;
+                                let mapped_y = (map_size.y - 1 - y) as i32 + tile_origin.y;
 
-                                let mapped_x = x as i32;
-                                let mapped_y = mapped_y as i32;
-
-                                let layer_tile = match layer_data.get_tile(mapped_x, mapped_y) {
+                                let layer_tile = match tile_layer.get_tile(mapped_x, mapped_y) {
                                     Some(t) => t,
                                     None => {
                                         continue;
@@ -347,22 +817,25 @@ pub fn process_loaded_maps(
                                     continue;
                                 }
                                 let layer_tile_data =
-                                    match layer_data.get_tile_data(mapped_x, mapped_y) {
+                                    match tile_layer.get_tile_data(mapped_x, mapped_y) {
                                         Some(d) => d,
                                         None => {
                                             continue;
                                         }
                                     };
 
-                                let texture_index = match tilemap_texture {
-                                    TilemapTexture::Single(_) => layer_tile.id(),
-                                    #[cfg(not(feature = "atlas"))]
-                                    TilemapTexture::Vector(_) =>
-                                        *tiled_map.tile_image_offsets.get(&(tileset_index, layer_tile.id()))
-                                        .expect("The offset into to image vector should have been saved during the initial load."),
-                                    #[cfg(not(feature = "atlas"))]
-                                    _ => unreachable!()
+                                let resolve_texture_index = |tile_id: tiled::TileId| -> u32 {
+                                    match tilemap_texture {
+                                        TilemapTexture::Single(_) => tile_id,
+                                        #[cfg(not(feature = "atlas"))]
+                                        TilemapTexture::Vector(_) =>
+                                            *tiled_map.tile_image_offsets.get(&(tileset_index, tile_id))
+                                            .expect("The offset into to image vector should have been saved during the initial load."),
+                                        #[cfg(not(feature = "atlas"))]
+                                        _ => unreachable!()
+                                    }
                                 };
+                                let texture_index = resolve_texture_index(layer_tile.id());
 
                                 let tile_pos = TilePos { x, y };
                                 let tile_entity = commands
@@ -379,9 +852,87 @@ pub fn process_loaded_maps(
                                     })
                                     .id();
                                 tile_storage.set(&tile_pos, tile_entity);
+
+                                if let Some(tile_def) = tileset.get_tile(layer_tile.id()) {
+                                    if let Some(type_id) = class_registry
+                                        .as_ref()
+                                        .and_then(|r| r.tiles.get(&tile_def.user_type))
+                                    {
+                                        insert_tiled_class_component(
+                                            &mut commands,
+                                            tile_entity,
+                                            *type_id,
+                                            tile_def.properties.clone(),
+                                        );
+                                    }
+                                }
+
+                                if let Some(frames) =
+                                    tiled_map.tile_animations.get(&(tileset_index, layer_tile.id()))
+                                {
+                                    if frames.is_empty() {
+                                        log::warn!(
+                                            "Skipping zero-frame animation of tile ({tileset_index}, {}).",
+                                            layer_tile.id()
+                                        );
+                                        continue;
+                                    }
+
+                                    let resolved_frames: Vec<(u32, u32)> = frames
+                                        .iter()
+                                        .map(|frame| {
+                                            (resolve_texture_index(frame.tile_id), frame.duration)
+                                        })
+                                        .collect();
+
+                                    // Tiled allows arbitrary frame ordering, but a contiguous,
+                                    // evenly-timed sequence of *resolved* texture indices can use
+                                    // bevy_ecs_tilemap's own (cheaper) AnimatedTile instead of our
+                                    // per-tile timer. Raw tile ids being contiguous isn't enough:
+                                    // for `TilemapTexture::Vector`, resolved indices are assigned
+                                    // by per-tile insertion order and don't necessarily track tile
+                                    // id spacing, so only `Single` textures take this path.
+                                    let is_contiguous = matches!(tilemap_texture, TilemapTexture::Single(_))
+                                        && resolved_frames.windows(2).all(|w| {
+                                            w[1].0 == w[0].0 + 1 && w[1].1 == w[0].1
+                                        });
+
+                                    if is_contiguous {
+                                        let start = resolved_frames[0].0;
+                                        commands.entity(tile_entity).insert(AnimatedTile {
+                                            start,
+                                            end: start + resolved_frames.len() as u32,
+                                            speed: 1000.0 / resolved_frames[0].1 as f32,
+                                        });
+                                    } else {
+                                        let resolved_frames = resolved_frames
+                                            .into_iter()
+                                            .map(|(index, duration_ms)| {
+                                                (index, Duration::from_millis(duration_ms as u64))
+                                            })
+                                            .collect();
+                                        if let Some(animated_tile) =
+                                            TiledAnimatedTile::new(resolved_frames)
+                                        {
+                                            // Start on the first frame, not the tile's own image.
+                                            commands.entity(tile_entity).insert((
+                                                TileTextureIndex(animated_tile.frames[0].0),
+                                                animated_tile,
+                                            ));
+                                        }
+                                    }
+                                }
                             }
                         }
 
+                        // Infinite layers were re-indexed so their minimum chunk corner sits at
+                        // (0, 0); translate the layer back out to its original TMX position.
+                        let chunk_origin_translation = Transform::from_xyz(
+                            tile_origin.x as f32 * grid_size.x,
+                            -(tile_origin.y as f32) * grid_size.y,
+                            0.0,
+                        );
+
                         commands.entity(layer_entity).insert(TilemapBundle {
                             grid_size,
                             size: map_size,
@@ -389,12 +940,15 @@ pub fn process_loaded_maps(
                             texture: tilemap_texture.clone(),
                             tile_size,
                             spacing: tile_spacing,
+                            color: layer_color,
                             transform: get_tilemap_center_transform(
                                 &map_size,
                                 &grid_size,
                                 &map_type,
                                 layer_index as f32,
-                            ) * Transform::from_xyz(offset_x, -offset_y, 0.0),
+                            ) * Transform::from_xyz(offset_x, -offset_y, 0.0)
+                                * chunk_origin_translation
+                                * tileset_offset,
                             map_type,
                             render_settings: *render_settings,
                             ..Default::default()